@@ -0,0 +1,315 @@
+//! Reconstructs `tool_call`/`tool_result` pairs into a nested call tree so a
+//! multi-step agent turn (call -> sub-call -> result) can be followed instead
+//! of read as a flat list.
+
+use crate::models::LogEntry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Clone)]
+pub struct CallNode {
+    pub call_index: usize,
+    pub result_index: Option<usize>,
+    pub children: Vec<CallNode>,
+    pub collapsed: bool,
+}
+
+impl CallNode {
+    fn new(call_index: usize, result_index: Option<usize>) -> Self {
+        CallNode {
+            call_index,
+            result_index,
+            children: Vec::new(),
+            collapsed: false,
+        }
+    }
+
+    /// A node is an error if its own result failed or any descendant did.
+    pub fn is_error(&self, entries: &[LogEntry]) -> bool {
+        let own_error = self
+            .result_index
+            .and_then(|i| entries[i].parse_tool_result())
+            .map(|r| r.is_error)
+            .unwrap_or(false);
+
+        own_error || self.children.iter().any(|c| c.is_error(entries))
+    }
+
+    /// Wall-clock time between this call and its matched result, in ms.
+    pub fn own_latency_ms(&self, entries: &[LogEntry]) -> Option<i64> {
+        let result_index = self.result_index?;
+        let call_ms = parse_time_of_day_ms(&entries[self.call_index].timestamp)?;
+        let result_ms = parse_time_of_day_ms(&entries[result_index].timestamp)?;
+        Some((result_ms - call_ms).max(0))
+    }
+
+    /// This node's own latency plus every descendant's, so a parent call
+    /// reflects the full cost of the sub-calls it spawned.
+    pub fn aggregate_latency_ms(&self, entries: &[LogEntry]) -> i64 {
+        let own = self.own_latency_ms(entries).unwrap_or(0);
+        let children: i64 = self
+            .children
+            .iter()
+            .map(|c| c.aggregate_latency_ms(entries))
+            .sum();
+        own + children
+    }
+}
+
+#[derive(Clone)]
+pub struct CallTree {
+    pub roots: Vec<CallNode>,
+}
+
+/// Makes two passes over `entries`: the first indexes every `tool_result` by
+/// `tool_call_id` and by tool name, the second pairs each `tool_call` with
+/// its `tool_result` and nests calls by `indent` level.
+pub fn build_call_tree(entries: &[LogEntry]) -> CallTree {
+    let mut by_id: HashMap<String, VecDeque<usize>> = HashMap::new();
+    let mut by_name: HashMap<String, VecDeque<usize>> = HashMap::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        let Some(result) = entry.parse_tool_result() else {
+            continue;
+        };
+        if let Some(id) = result.tool_call_id {
+            by_id.entry(id).or_default().push_back(idx);
+        }
+        by_name.entry(result.tool_name).or_default().push_back(idx);
+    }
+
+    let mut used_results: HashSet<usize> = HashSet::new();
+    let mut stack: Vec<(u32, CallNode)> = Vec::new();
+    let mut roots: Vec<CallNode> = Vec::new();
+
+    for (idx, entry) in entries.iter().enumerate() {
+        let Some(call) = entry.parse_tool_call() else {
+            continue;
+        };
+        let indent = call.indent.unwrap_or(0);
+        let result_index = find_matching_result(
+            idx,
+            call.tool_call_id.as_deref(),
+            &call.tool_name,
+            &mut by_id,
+            &mut by_name,
+            &mut used_results,
+        );
+
+        while let Some(&(top_indent, _)) = stack.last() {
+            if top_indent >= indent {
+                let (_, popped) = stack.pop().unwrap();
+                attach(&mut stack, &mut roots, popped);
+            } else {
+                break;
+            }
+        }
+
+        stack.push((indent, CallNode::new(idx, result_index)));
+    }
+
+    while let Some((_, popped)) = stack.pop() {
+        attach(&mut stack, &mut roots, popped);
+    }
+
+    CallTree { roots }
+}
+
+fn attach(stack: &mut [(u32, CallNode)], roots: &mut Vec<CallNode>, node: CallNode) {
+    if let Some((_, parent)) = stack.last_mut() {
+        parent.children.push(node);
+    } else {
+        roots.push(node);
+    }
+}
+
+/// Pops the next unused, not-yet-passed result index off `queue`, in O(1)
+/// amortized time: each entry is discarded from the queue at most once,
+/// whether because it's stale (before `call_index`) or because the other
+/// index (id vs. name) already claimed it.
+fn pop_next_match(
+    queue: &mut VecDeque<usize>,
+    call_index: usize,
+    used: &HashSet<usize>,
+) -> Option<usize> {
+    while let Some(&front) = queue.front() {
+        if front <= call_index || used.contains(&front) {
+            queue.pop_front();
+        } else {
+            break;
+        }
+    }
+    queue.pop_front()
+}
+
+fn find_matching_result(
+    call_index: usize,
+    tool_call_id: Option<&str>,
+    tool_name: &str,
+    by_id: &mut HashMap<String, VecDeque<usize>>,
+    by_name: &mut HashMap<String, VecDeque<usize>>,
+    used: &mut HashSet<usize>,
+) -> Option<usize> {
+    if let Some(id) = tool_call_id {
+        if let Some(queue) = by_id.get_mut(id) {
+            if let Some(i) = pop_next_match(queue, call_index, used) {
+                used.insert(i);
+                return Some(i);
+            }
+        }
+    }
+
+    let queue = by_name.get_mut(tool_name)?;
+    let i = pop_next_match(queue, call_index, used)?;
+    used.insert(i);
+    Some(i)
+}
+
+/// Flattened (path, depth) pairs for every node visible given the current
+/// collapse state, in display order.
+pub fn visible_nodes(tree: &CallTree) -> Vec<(Vec<usize>, usize)> {
+    fn walk(nodes: &[CallNode], prefix: &mut Vec<usize>, depth: usize, out: &mut Vec<(Vec<usize>, usize)>) {
+        for (i, node) in nodes.iter().enumerate() {
+            prefix.push(i);
+            out.push((prefix.clone(), depth));
+            if !node.collapsed {
+                walk(&node.children, prefix, depth + 1, out);
+            }
+            prefix.pop();
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&tree.roots, &mut Vec::new(), 0, &mut out);
+    out
+}
+
+pub fn node_at<'a>(tree: &'a CallTree, path: &[usize]) -> Option<&'a CallNode> {
+    let (&first, rest) = path.split_first()?;
+    let mut node = tree.roots.get(first)?;
+    for &i in rest {
+        node = node.children.get(i)?;
+    }
+    Some(node)
+}
+
+pub fn node_at_mut<'a>(tree: &'a mut CallTree, path: &[usize]) -> Option<&'a mut CallNode> {
+    let (&first, rest) = path.split_first()?;
+    let mut node = tree.roots.get_mut(first)?;
+    for &i in rest {
+        node = node.children.get_mut(i)?;
+    }
+    Some(node)
+}
+
+/// Parses the time-of-day portion of an ISO-8601 timestamp into milliseconds,
+/// matching the `split('T')` convention the timeline view already uses for
+/// display.
+fn parse_time_of_day_ms(timestamp: &str) -> Option<i64> {
+    let time_part = timestamp.split('T').nth(1)?;
+    let mut fields = time_part.trim_end_matches('Z').split(':');
+    let hours: i64 = fields.next()?.parse().ok()?;
+    let minutes: i64 = fields.next()?.parse().ok()?;
+    let seconds: f64 = fields.next()?.parse().ok()?;
+    Some(hours * 3_600_000 + minutes * 60_000 + (seconds * 1000.0) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogEntry;
+    use serde_json::json;
+
+    fn call(tool_name: &str, indent: u32, tool_call_id: Option<&str>) -> LogEntry {
+        LogEntry {
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            session_id: None,
+            event_type: "tool_call".to_string(),
+            level: "info".to_string(),
+            agent_name: None,
+            agent_type: None,
+            data: Some(json!({
+                "tool_name": tool_name,
+                "indent": indent,
+                "tool_call_id": tool_call_id,
+            })),
+        }
+    }
+
+    fn result(tool_name: &str, tool_call_id: Option<&str>, is_error: bool) -> LogEntry {
+        LogEntry {
+            timestamp: "2024-01-01T00:00:01Z".to_string(),
+            session_id: None,
+            event_type: "tool_result".to_string(),
+            level: "info".to_string(),
+            agent_name: None,
+            agent_type: None,
+            data: Some(json!({
+                "tool_name": tool_name,
+                "result_content": "ok",
+                "is_error": is_error,
+                "tool_call_id": tool_call_id,
+            })),
+        }
+    }
+
+    #[test]
+    fn same_indent_calls_are_siblings() {
+        let entries = vec![
+            call("a", 0, None),
+            result("a", None, false),
+            call("b", 0, None),
+            result("b", None, false),
+        ];
+        let tree = build_call_tree(&entries);
+        assert_eq!(tree.roots.len(), 2);
+        assert!(tree.roots[0].children.is_empty());
+        assert!(tree.roots[1].children.is_empty());
+    }
+
+    #[test]
+    fn deeper_indent_call_attaches_as_child() {
+        let entries = vec![
+            call("outer", 0, None),
+            call("inner", 1, None),
+            result("inner", None, false),
+            result("outer", None, false),
+        ];
+        let tree = build_call_tree(&entries);
+        assert_eq!(tree.roots.len(), 1);
+        assert_eq!(tree.roots[0].children.len(), 1);
+        assert_eq!(tree.roots[0].children[0].call_index, 1);
+    }
+
+    #[test]
+    fn matches_result_by_tool_call_id_over_name() {
+        let entries = vec![
+            call("search", 0, Some("id-2")),
+            result("search", Some("id-1"), false), // wrong id, same name
+            result("search", Some("id-2"), false), // correct id
+        ];
+        let tree = build_call_tree(&entries);
+        assert_eq!(tree.roots[0].result_index, Some(2));
+    }
+
+    #[test]
+    fn falls_back_to_matching_by_name_without_id() {
+        let entries = vec![call("search", 0, None), result("search", None, false)];
+        let tree = build_call_tree(&entries);
+        assert_eq!(tree.roots[0].result_index, Some(1));
+    }
+
+    #[test]
+    fn visible_nodes_skips_collapsed_children() {
+        let entries = vec![
+            call("outer", 0, None),
+            call("inner", 1, None),
+            result("inner", None, false),
+            result("outer", None, false),
+        ];
+        let mut tree = build_call_tree(&entries);
+        tree.roots[0].collapsed = true;
+        assert_eq!(visible_nodes(&tree).len(), 1);
+
+        tree.roots[0].collapsed = false;
+        assert_eq!(visible_nodes(&tree).len(), 2);
+    }
+}
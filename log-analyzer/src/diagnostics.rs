@@ -0,0 +1,292 @@
+//! A small lint-style analysis subsystem: each `Rule` walks the full entry
+//! list once and emits `Diagnostic`s, computed once at load and then
+//! browsable like search matches.
+
+use crate::models::LogEntry;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Diagnostic {
+    pub entry_index: usize,
+    pub severity: Severity,
+    pub rule_name: String,
+    pub message: String,
+}
+
+pub trait Rule {
+    fn name(&self) -> &'static str;
+    fn check(&self, entries: &[LogEntry]) -> Vec<Diagnostic>;
+}
+
+/// Flags the same tool failing `threshold` or more times in a row.
+pub struct RepeatedToolErrorRule {
+    pub threshold: u32,
+}
+
+impl Rule for RepeatedToolErrorRule {
+    fn name(&self) -> &'static str {
+        "repeated-tool-error"
+    }
+
+    fn check(&self, entries: &[LogEntry]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut streak_tool: Option<String> = None;
+        let mut streak_len = 0u32;
+        let mut streak_start = 0usize;
+
+        for (idx, entry) in entries.iter().enumerate() {
+            let Some(result) = entry.parse_tool_result() else {
+                continue;
+            };
+
+            if result.is_error && streak_tool.as_deref() == Some(result.tool_name.as_str()) {
+                streak_len += 1;
+            } else if result.is_error {
+                streak_tool = Some(result.tool_name.clone());
+                streak_len = 1;
+                streak_start = idx;
+            } else {
+                streak_tool = None;
+                streak_len = 0;
+            }
+
+            if result.is_error && streak_len == self.threshold {
+                diagnostics.push(Diagnostic {
+                    entry_index: idx,
+                    severity: Severity::Error,
+                    rule_name: self.name().to_string(),
+                    message: format!(
+                        "\"{}\" failed {} times in a row (starting at entry {})",
+                        result.tool_name, streak_len, streak_start + 1
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags cumulative LLM token usage crossing a configured budget.
+pub struct TokenBudgetRule {
+    pub budget: u64,
+}
+
+impl Rule for TokenBudgetRule {
+    fn name(&self) -> &'static str {
+        "token-budget"
+    }
+
+    fn check(&self, entries: &[LogEntry]) -> Vec<Diagnostic> {
+        let mut cumulative = 0u64;
+        let mut warned = false;
+        let mut diagnostics = Vec::new();
+
+        for (idx, entry) in entries.iter().enumerate() {
+            let Some(llm) = entry.parse_llm_response() else {
+                continue;
+            };
+            let Some(total) = llm.tokens.and_then(|t| t.total) else {
+                continue;
+            };
+
+            cumulative += total as u64;
+            if !warned && cumulative > self.budget {
+                warned = true;
+                diagnostics.push(Diagnostic {
+                    entry_index: idx,
+                    severity: Severity::Warning,
+                    rule_name: self.name().to_string(),
+                    message: format!(
+                        "Cumulative token usage crossed budget of {} (now {})",
+                        self.budget, cumulative
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags a `tool_call` repeating the same tool+params as a prior call,
+/// suggesting the agent is stuck in a loop.
+pub struct SuspectedLoopRule;
+
+impl Rule for SuspectedLoopRule {
+    fn name(&self) -> &'static str {
+        "suspected-loop"
+    }
+
+    fn check(&self, entries: &[LogEntry]) -> Vec<Diagnostic> {
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut diagnostics = Vec::new();
+
+        for (idx, entry) in entries.iter().enumerate() {
+            let Some(call) = entry.parse_tool_call() else {
+                continue;
+            };
+
+            let mut params: Vec<_> = call.params.iter().collect();
+            params.sort_by(|a, b| a.0.cmp(b.0));
+            let key = format!("{}:{:?}", call.tool_name, params);
+
+            if let Some(&prev_idx) = seen.get(&key) {
+                diagnostics.push(Diagnostic {
+                    entry_index: idx,
+                    severity: Severity::Warning,
+                    rule_name: self.name().to_string(),
+                    message: format!(
+                        "\"{}\" called with identical params as entry {}",
+                        call.tool_name,
+                        prev_idx + 1
+                    ),
+                });
+            }
+
+            seen.insert(key, idx);
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags an `execution_summary` that reports failure.
+pub struct ExecutionFailureRule;
+
+impl Rule for ExecutionFailureRule {
+    fn name(&self) -> &'static str {
+        "execution-failure"
+    }
+
+    fn check(&self, entries: &[LogEntry]) -> Vec<Diagnostic> {
+        entries
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, entry)| {
+                let summary = entry.parse_execution_summary()?;
+                if summary.success {
+                    return None;
+                }
+                Some(Diagnostic {
+                    entry_index: idx,
+                    severity: Severity::Error,
+                    rule_name: self.name().to_string(),
+                    message: format!("Execution failed: {}", summary.termination_reason),
+                })
+            })
+            .collect()
+    }
+}
+
+pub struct DiagnosticEngine {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl DiagnosticEngine {
+    pub fn with_default_rules() -> Self {
+        DiagnosticEngine {
+            rules: vec![
+                Box::new(RepeatedToolErrorRule { threshold: 3 }),
+                Box::new(TokenBudgetRule { budget: 100_000 }),
+                Box::new(SuspectedLoopRule),
+                Box::new(ExecutionFailureRule),
+            ],
+        }
+    }
+
+    /// Runs every rule once and returns diagnostics ordered by entry index.
+    pub fn run(&self, entries: &[LogEntry]) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> =
+            self.rules.iter().flat_map(|rule| rule.check(entries)).collect();
+        diagnostics.sort_by_key(|d| d.entry_index);
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LogEntry;
+    use serde_json::json;
+
+    fn tool_result(tool_name: &str, is_error: bool) -> LogEntry {
+        LogEntry {
+            timestamp: "2024-01-01T00:00:00Z".to_string(),
+            session_id: None,
+            event_type: "tool_result".to_string(),
+            level: "info".to_string(),
+            agent_name: None,
+            agent_type: None,
+            data: Some(json!({
+                "tool_name": tool_name,
+                "result_content": "oops",
+                "is_error": is_error,
+            })),
+        }
+    }
+
+    #[test]
+    fn fires_exactly_at_the_threshold_streak_length() {
+        let entries = vec![
+            tool_result("search", true),
+            tool_result("search", true),
+            tool_result("search", true),
+        ];
+        let diagnostics = RepeatedToolErrorRule { threshold: 3 }.check(&entries);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].entry_index, 2);
+    }
+
+    #[test]
+    fn does_not_fire_below_the_threshold() {
+        let entries = vec![tool_result("search", true), tool_result("search", true)];
+        let diagnostics = RepeatedToolErrorRule { threshold: 3 }.check(&entries);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn does_not_fire_again_past_the_threshold() {
+        let entries = vec![
+            tool_result("search", true),
+            tool_result("search", true),
+            tool_result("search", true),
+            tool_result("search", true),
+            tool_result("search", true),
+        ];
+        let diagnostics = RepeatedToolErrorRule { threshold: 3 }.check(&entries);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].entry_index, 2);
+    }
+
+    #[test]
+    fn streak_resets_on_success_or_different_tool() {
+        let entries = vec![
+            tool_result("search", true),
+            tool_result("search", true),
+            tool_result("search", false),
+            tool_result("search", true),
+            tool_result("fetch", true),
+            tool_result("fetch", true),
+        ];
+        let diagnostics = RepeatedToolErrorRule { threshold: 3 }.check(&entries);
+        assert!(diagnostics.is_empty());
+    }
+}
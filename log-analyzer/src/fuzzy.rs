@@ -0,0 +1,200 @@
+//! Subsequence fuzzy matching (Sublime/Zed style) used to jump to log
+//! entries by content with `/`.
+
+use crate::models::LogEntry;
+
+const START_BONUS: i32 = 20;
+const WORD_BOUNDARY_BONUS: i32 = 10;
+const CONSECUTIVE_BONUS: i32 = 15;
+const GAP_PENALTY: i32 = 2;
+const LEADING_PENALTY: i32 = 1;
+
+/// Result of matching a query against a single candidate string.
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// Char (not byte) indices into the original (non-lowercased) candidate —
+    /// offsets into its `chars()` sequence, so slicing the source `&str` with
+    /// these requires walking chars rather than indexing bytes directly.
+    pub positions: Vec<usize>,
+}
+
+/// A match tied back to the entry it was found in.
+#[derive(Clone)]
+pub struct RankedMatch {
+    pub entry_index: usize,
+    pub positions: Vec<usize>,
+}
+
+/// 64-bit mask with one bit per lowercase letter/digit present in `s`, used
+/// to reject non-candidates in O(1) before running the full match.
+pub fn char_bag(s: &str) -> u64 {
+    let mut bag = 0u64;
+    for c in s.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() {
+            bag |= 1 << (c as u32 - 'a' as u32);
+        } else if c.is_ascii_digit() {
+            bag |= 1 << (26 + (c as u32 - '0' as u32));
+        }
+    }
+    bag
+}
+
+fn is_word_boundary(chars: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    let prev = chars[idx - 1];
+    let cur = chars[idx];
+    prev == '_' || prev == '-' || prev == ' ' || (prev.is_lowercase() && cur.is_uppercase())
+}
+
+/// Case-insensitive subsequence match: every query char must appear in order
+/// in `candidate`. Returns `None` if the query doesn't fit or is empty.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() || query.chars().count() > candidate.chars().count() {
+        return None;
+    }
+
+    if char_bag(query) & char_bag(candidate) != char_bag(query) {
+        return None;
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    // Lowercase char by char so indices stay aligned with `candidate_chars`.
+    let candidate_lower: Vec<char> = candidate_chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut positions = Vec::with_capacity(query_lower.len());
+    let mut score = 0i32;
+    let mut last_matched: Option<usize> = None;
+    let mut search_from = 0usize;
+
+    for &qc in &query_lower {
+        let ci = (search_from..candidate_lower.len()).find(|&ci| candidate_lower[ci] == qc)?;
+
+        if ci == 0 {
+            score += START_BONUS;
+        }
+        if is_word_boundary(&candidate_chars, ci) {
+            score += WORD_BOUNDARY_BONUS;
+        }
+        match last_matched {
+            Some(last) if ci == last + 1 => score += CONSECUTIVE_BONUS,
+            Some(last) => score -= (ci - last - 1) as i32 * GAP_PENALTY,
+            None => score -= ci as i32 * LEADING_PENALTY,
+        }
+
+        positions.push(ci);
+        last_matched = Some(ci);
+        search_from = ci + 1;
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Flattens the fields a user would plausibly search for into one string.
+fn flatten_entry(entry: &LogEntry) -> String {
+    let mut parts = vec![entry.event_type.clone()];
+
+    if let Some(tool_call) = entry.parse_tool_call() {
+        parts.push(tool_call.tool_name);
+    }
+    if let Some(tool_result) = entry.parse_tool_result() {
+        parts.push(tool_result.tool_name);
+        parts.push(tool_result.result_content);
+    }
+    if let Some(llm) = entry.parse_llm_response() {
+        parts.push(llm.model);
+        if let Some(content) = llm.content {
+            parts.push(content);
+        }
+        if let Some(reasoning) = llm.reasoning {
+            parts.push(reasoning);
+        }
+    }
+    if let Some(action) = entry.parse_agent_action() {
+        parts.push(action.action);
+        if let Some(details) = action.details {
+            parts.push(details);
+        }
+    }
+
+    parts.join(" ")
+}
+
+/// Matches `query` against every entry and returns matches ranked best-first.
+/// An empty query clears matches entirely.
+pub fn search_entries(entries: &[LogEntry], query: &str) -> Vec<RankedMatch> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranked: Vec<(i32, RankedMatch)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(entry_index, entry)| {
+            let haystack = flatten_entry(entry);
+            fuzzy_match(query, &haystack).map(|m| {
+                (
+                    m.score,
+                    RankedMatch {
+                        entry_index,
+                        positions: m.positions,
+                    },
+                )
+            })
+        })
+        .collect();
+
+    ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    ranked.into_iter().map(|(_, m)| m).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_match_when_query_longer_than_candidate() {
+        assert!(fuzzy_match("abcd", "abc").is_none());
+    }
+
+    #[test]
+    fn empty_query_does_not_match() {
+        assert!(fuzzy_match("", "abc").is_none());
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher_than_scattered() {
+        let consecutive = fuzzy_match("ab", "ab cd").unwrap();
+        let scattered = fuzzy_match("ab", "a b").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn word_boundary_match_scores_higher_than_mid_word() {
+        // "tc" matches "tool_call" at the word boundary (t, c after `_`)
+        // vs. "oc", whose 'o' matches mid-word (only its 'c' is at a boundary).
+        let boundary = fuzzy_match("tc", "tool_call").unwrap();
+        let mid_word = fuzzy_match("oc", "tool_call").unwrap();
+        assert!(boundary.score > mid_word.score);
+    }
+
+    #[test]
+    fn match_at_start_scores_higher_than_later_start() {
+        let at_start = fuzzy_match("to", "tool_call").unwrap();
+        let later_start = fuzzy_match("ll", "tool_call").unwrap();
+        assert!(at_start.score > later_start.score);
+    }
+
+    #[test]
+    fn positions_are_in_order_and_cover_every_query_char() {
+        let m = fuzzy_match("tlc", "tool_call").unwrap();
+        assert_eq!(m.positions.len(), 3);
+        assert!(m.positions.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -1,22 +1,52 @@
+mod call_tree;
+mod diagnostics;
+mod fuzzy;
 mod models;
+mod theme;
 mod ui;
 
 use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use models::*;
 use ratatui::{
     backend::{Backend, CrosstermBackend},
+    layout::Rect,
     Terminal,
 };
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
 use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Max gap between two left-clicks on the same row to count as a double-click.
+const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
+/// Rows assumed visible in the timeline when auto-scrolling, and for paging
+/// before the first frame is drawn (see `page_size`). Matches the main
+/// content area's typical height; not read from the terminal since key
+/// handling runs ahead of the next `terminal.draw`.
+const VISIBLE_ROWS: usize = 20;
+
+/// A page- or edge-relative jump in the timeline, as opposed to the
+/// single-step `j/k` movement.
+enum PageMovement {
+    FullDown,
+    FullUp,
+    HalfDown,
+    HalfUp,
+    Home,
+    End,
+}
 
 #[derive(Parser)]
 #[command(name = "log-analyzer")]
@@ -24,6 +54,10 @@ use std::path::PathBuf;
 struct Cli {
     /// Path to the JSONL log file
     file: PathBuf,
+
+    /// Path to a TOML or JSON theme config overlaying the built-in colors
+    #[arg(long)]
+    theme: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -32,17 +66,71 @@ pub struct AppState {
     pub selected_index: usize,
     pub scroll_offset: usize,
     pub details_scroll_offset: usize,  // New: scroll position for details panel
+    /// Whether j/k scroll the details pane instead of moving the timeline
+    /// selection; the wheel follows the pointer regardless. Enter toggles it
+    /// in the Details view, Esc clears it.
+    pub details_focused: bool,
     pub tool_stats: ToolStats,
     pub token_stats: TokenStats,
     pub view_mode: ViewMode,
     pub filter_event_type: Option<String>,
     pub count_prefix: String,
+    pub input_mode: InputMode,
+    pub search_query: String,
+    pub search_matches: Vec<fuzzy::RankedMatch>,
+    pub search_cursor: usize,
+    pub call_tree: call_tree::CallTree,
+    pub call_tree_selected: usize,
+    pub call_tree_scroll: usize,
+    /// Selection/scroll for the non-timeline tabs (Tool Analytics, LLM
+    /// Breakdown, Session Overview), reset whenever the tab changes.
+    pub tab_selected: usize,
+    pub tab_scroll: usize,
+    pub diagnostics: Vec<diagnostics::Diagnostic>,
+    pub diagnostics_only: bool,
+    /// Index into `diagnostics` for `]`/`[` next/prev-diagnostic jumps.
+    pub diagnostic_cursor: usize,
+    pub theme: theme::Theme,
+    pub tabs: TabsState,
+    /// Rendered `Rect`s of the timeline/details panes from the last frame,
+    /// used to hit-test mouse clicks and scroll events against screen position.
+    pub timeline_area: Rect,
+    pub details_area: Rect,
+    pub last_click: Option<(Instant, usize)>,
 }
 
 #[derive(Clone, PartialEq)]
 pub enum ViewMode {
     Timeline,
     Details,
+    CallTree,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum InputMode {
+    Normal,
+    Search,
+}
+
+/// Top tab strip shown above the stats panel; cycled with Tab/Shift-Tab.
+#[derive(Clone)]
+pub struct TabsState {
+    pub titles: Vec<String>,
+    pub index: usize,
+}
+
+impl TabsState {
+    pub fn new(titles: Vec<String>) -> TabsState {
+        TabsState { titles, index: 0 }
+    }
+
+    pub fn next(&mut self) {
+        self.index = (self.index + 1) % self.titles.len();
+    }
+
+    pub fn previous(&mut self) {
+        self.index = (self.index + self.titles.len() - 1) % self.titles.len();
+    }
 }
 
 #[derive(Clone)]
@@ -52,11 +140,18 @@ pub struct ToolStats {
     pub errors: HashMap<String, u32>,
 }
 
+#[derive(Clone)]
+pub struct ModelStats {
+    pub calls: u32,
+    pub tokens: u64,
+}
+
 #[derive(Clone)]
 pub struct TokenStats {
     pub total_tokens: u64,
     pub total_calls: u32,
     pub by_agent: HashMap<String, u64>,
+    pub by_model: HashMap<String, ModelStats>,
 }
 
 fn main() -> Result<()> {
@@ -70,17 +165,44 @@ fn main() -> Result<()> {
 
     let tool_stats = calculate_tool_stats(&entries);
     let token_stats = calculate_token_stats(&entries);
+    let call_tree = call_tree::build_call_tree(&entries);
+    let diagnostics = diagnostics::DiagnosticEngine::with_default_rules().run(&entries);
+    let theme = theme::Theme::load(cli.theme.as_deref());
+    let tabs = TabsState::new(
+        ["Timeline", "Tool Analytics", "LLM Breakdown", "Session Overview"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    );
 
     let mut app_state = AppState {
         entries,
         selected_index: 0,
         scroll_offset: 0,
         details_scroll_offset: 0,
+        details_focused: false,
         tool_stats,
         token_stats,
         view_mode: ViewMode::Timeline,
         filter_event_type: None,
         count_prefix: String::new(),
+        input_mode: InputMode::Normal,
+        search_query: String::new(),
+        search_matches: Vec::new(),
+        search_cursor: 0,
+        call_tree,
+        call_tree_selected: 0,
+        call_tree_scroll: 0,
+        tab_selected: 0,
+        tab_scroll: 0,
+        diagnostics,
+        diagnostics_only: false,
+        diagnostic_cursor: 0,
+        theme,
+        tabs,
+        timeline_area: Rect::default(),
+        details_area: Rect::default(),
+        last_click: None,
     };
 
     // Setup terminal
@@ -112,14 +234,81 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
     loop {
         terminal.draw(|f| ui::draw_ui(f, app_state))?;
 
-        if let Event::Key(key) = event::read()? {
+        let event = event::read()?;
+
+        if let Event::Mouse(mouse) = event {
+            handle_mouse_event(app_state, mouse);
+            continue;
+        }
+
+        if let Event::Key(key) = event {
             if key.kind == KeyEventKind::Press {
+                if key.code == KeyCode::Tab {
+                    app_state.tabs.next();
+                    app_state.tab_selected = 0;
+                    app_state.tab_scroll = 0;
+                    continue;
+                }
+                if key.code == KeyCode::BackTab {
+                    app_state.tabs.previous();
+                    app_state.tab_selected = 0;
+                    app_state.tab_scroll = 0;
+                    continue;
+                }
+
+                if app_state.input_mode == InputMode::Search {
+                    handle_search_key(app_state, key.code);
+                    continue;
+                }
+
+                if app_state.tabs.index != 0 {
+                    if handle_analytics_key(app_state, key.code) {
+                        return Ok(());
+                    }
+                    continue;
+                }
+
+                if app_state.view_mode == ViewMode::CallTree {
+                    if handle_call_tree_key(app_state, key.code) {
+                        return Ok(());
+                    }
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Char('q') => return Ok(()),
                     KeyCode::Char(c) if c.is_ascii_digit() => {
                         // Build up count prefix
                         app_state.count_prefix.push(c);
                     }
+                    KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::FullDown);
+                    }
+                    KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::FullUp);
+                    }
+                    KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::HalfDown);
+                    }
+                    KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::HalfUp);
+                    }
+                    KeyCode::Char('j') | KeyCode::Down if app_state.details_focused => {
+                        let count = app_state.count_prefix.parse::<usize>().unwrap_or(1);
+                        app_state.count_prefix.clear();
+                        app_state.details_scroll_offset =
+                            app_state.details_scroll_offset.saturating_add(count);
+                    }
+                    KeyCode::Char('k') | KeyCode::Up if app_state.details_focused => {
+                        let count = app_state.count_prefix.parse::<usize>().unwrap_or(1);
+                        app_state.count_prefix.clear();
+                        app_state.details_scroll_offset =
+                            app_state.details_scroll_offset.saturating_sub(count);
+                    }
                     KeyCode::Char('j') | KeyCode::Down => {
                         let count = app_state.count_prefix.parse::<usize>().unwrap_or(1);
                         app_state.count_prefix.clear();
@@ -132,9 +321,8 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
                         // Reset details scroll when changing selection
                         app_state.details_scroll_offset = 0;
                         // Auto-scroll
-                        let visible_height = 20;
-                        if app_state.selected_index >= app_state.scroll_offset + visible_height {
-                            app_state.scroll_offset = app_state.selected_index.saturating_sub(visible_height - 1);
+                        if app_state.selected_index >= app_state.scroll_offset + VISIBLE_ROWS {
+                            app_state.scroll_offset = app_state.selected_index.saturating_sub(VISIBLE_ROWS - 1);
                         }
                     }
                     KeyCode::Char('k') | KeyCode::Up => {
@@ -165,12 +353,25 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
                         app_state.count_prefix.clear();
                         app_state.details_scroll_offset += 1;
                     }
+                    KeyCode::Enter if app_state.view_mode == ViewMode::Details => {
+                        app_state.count_prefix.clear();
+                        app_state.details_focused = !app_state.details_focused;
+                    }
                     KeyCode::Char('d') => {
                         app_state.count_prefix.clear();
                         app_state.view_mode = match app_state.view_mode {
-                            ViewMode::Timeline => ViewMode::Details,
+                            ViewMode::Timeline | ViewMode::CallTree => ViewMode::Details,
                             ViewMode::Details => ViewMode::Timeline,
                         };
+                        app_state.details_focused = false;
+                    }
+                    KeyCode::Char('c') => {
+                        app_state.count_prefix.clear();
+                        app_state.view_mode = match app_state.view_mode {
+                            ViewMode::CallTree => ViewMode::Timeline,
+                            ViewMode::Timeline | ViewMode::Details => ViewMode::CallTree,
+                        };
+                        app_state.details_focused = false;
                     }
                     KeyCode::Char('g') => {
                         app_state.count_prefix.clear();
@@ -180,7 +381,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
                     KeyCode::Char('G') => {
                         app_state.count_prefix.clear();
                         app_state.selected_index = app_state.entries.len().saturating_sub(1);
-                        app_state.scroll_offset = app_state.entries.len().saturating_sub(20).max(0);
+                        app_state.scroll_offset = app_state.entries.len().saturating_sub(VISIBLE_ROWS);
+                    }
+                    KeyCode::Home => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::Home);
+                    }
+                    KeyCode::End => {
+                        app_state.count_prefix.clear();
+                        apply_page_movement(app_state, PageMovement::End);
                     }
                     KeyCode::Char('f') => {
                         app_state.count_prefix.clear();
@@ -194,9 +403,51 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
                         app_state.selected_index = 0;
                         app_state.scroll_offset = 0;
                     }
+                    KeyCode::Char('x') => {
+                        app_state.count_prefix.clear();
+                        app_state.diagnostics_only = !app_state.diagnostics_only;
+                        app_state.selected_index = 0;
+                        app_state.scroll_offset = 0;
+                    }
+                    KeyCode::Char('/') => {
+                        app_state.count_prefix.clear();
+                        app_state.input_mode = InputMode::Search;
+                        app_state.search_query.clear();
+                        app_state.search_matches.clear();
+                        app_state.search_cursor = 0;
+                    }
+                    KeyCode::Char('n') if !app_state.search_matches.is_empty() => {
+                        app_state.count_prefix.clear();
+                        app_state.search_cursor =
+                            (app_state.search_cursor + 1) % app_state.search_matches.len();
+                        jump_to_search_match(app_state);
+                    }
+                    KeyCode::Char('N') if !app_state.search_matches.is_empty() => {
+                        app_state.count_prefix.clear();
+                        app_state.search_cursor = app_state
+                            .search_cursor
+                            .checked_sub(1)
+                            .unwrap_or(app_state.search_matches.len() - 1);
+                        jump_to_search_match(app_state);
+                    }
+                    KeyCode::Char(']') if !app_state.diagnostics.is_empty() => {
+                        app_state.count_prefix.clear();
+                        app_state.diagnostic_cursor =
+                            (app_state.diagnostic_cursor + 1) % app_state.diagnostics.len();
+                        jump_to_diagnostic(app_state);
+                    }
+                    KeyCode::Char('[') if !app_state.diagnostics.is_empty() => {
+                        app_state.count_prefix.clear();
+                        app_state.diagnostic_cursor = app_state
+                            .diagnostic_cursor
+                            .checked_sub(1)
+                            .unwrap_or(app_state.diagnostics.len() - 1);
+                        jump_to_diagnostic(app_state);
+                    }
                     KeyCode::Esc => {
-                        // Clear count prefix on escape
+                        // Clear count prefix and defocus the details pane
                         app_state.count_prefix.clear();
+                        app_state.details_focused = false;
                     }
                     _ => {
                         // Clear count prefix on any other key
@@ -208,21 +459,350 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app_state: &mut AppState) ->
     }
 }
 
+/// Handles mouse input: clicking a timeline row selects it (a second click on
+/// the same row within `DOUBLE_CLICK_WINDOW` toggles `ViewMode::Details`),
+/// and the wheel scrolls the timeline or the details pane depending on which
+/// one the pointer is over.
+fn handle_mouse_event(app_state: &mut AppState, mouse: MouseEvent) {
+    let in_area = |area: Rect| {
+        mouse.column >= area.x
+            && mouse.column < area.x.saturating_add(area.width)
+            && mouse.row >= area.y
+            && mouse.row < area.y.saturating_add(area.height)
+    };
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if in_area(app_state.timeline_area) => {
+            let row_offset = mouse.row.saturating_sub(app_state.timeline_area.y + 1) as usize;
+            let clicked_row = app_state.scroll_offset + row_offset;
+            if let Some(&clicked) = ui::filtered_entry_indices(app_state).get(clicked_row) {
+                let now = Instant::now();
+                let is_double_click = app_state
+                    .last_click
+                    .map(|(at, idx)| idx == clicked && now.duration_since(at) < DOUBLE_CLICK_WINDOW)
+                    .unwrap_or(false);
+
+                app_state.selected_index = clicked;
+                app_state.details_scroll_offset = 0;
+
+                if is_double_click {
+                    app_state.view_mode = match app_state.view_mode {
+                        ViewMode::Timeline | ViewMode::CallTree => ViewMode::Details,
+                        ViewMode::Details => ViewMode::Timeline,
+                    };
+                    app_state.details_focused = false;
+                    app_state.last_click = None;
+                } else {
+                    app_state.last_click = Some((now, clicked));
+                }
+            }
+        }
+        MouseEventKind::ScrollDown => {
+            if in_area(app_state.details_area) {
+                app_state.details_scroll_offset += 3;
+            } else if in_area(app_state.timeline_area) {
+                let max_scroll = ui::filtered_entry_indices(app_state)
+                    .len()
+                    .saturating_sub(VISIBLE_ROWS);
+                app_state.scroll_offset = (app_state.scroll_offset + 3).min(max_scroll);
+            }
+        }
+        MouseEventKind::ScrollUp => {
+            if in_area(app_state.details_area) {
+                app_state.details_scroll_offset = app_state.details_scroll_offset.saturating_sub(3);
+            } else if in_area(app_state.timeline_area) {
+                app_state.scroll_offset = app_state.scroll_offset.saturating_sub(3);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_search_key(app_state: &mut AppState, code: KeyCode) {
+    match code {
+        KeyCode::Enter => {
+            app_state.input_mode = InputMode::Normal;
+            if !app_state.search_matches.is_empty() {
+                app_state.search_cursor = 0;
+                jump_to_search_match(app_state);
+            }
+        }
+        KeyCode::Esc => {
+            app_state.input_mode = InputMode::Normal;
+            app_state.search_query.clear();
+            app_state.search_matches.clear();
+            app_state.search_cursor = 0;
+        }
+        KeyCode::Backspace => {
+            app_state.search_query.pop();
+            app_state.search_matches = fuzzy::search_entries(&app_state.entries, &app_state.search_query);
+            app_state.search_cursor = 0;
+        }
+        KeyCode::Char(c) => {
+            app_state.search_query.push(c);
+            app_state.search_matches = fuzzy::search_entries(&app_state.entries, &app_state.search_query);
+            app_state.search_cursor = 0;
+        }
+        _ => {}
+    }
+}
+
+/// Handles input while `ViewMode::CallTree` is active. Returns `true` if the
+/// app should quit.
+fn handle_call_tree_key(app_state: &mut AppState, code: KeyCode) -> bool {
+    let total = call_tree::visible_nodes(&app_state.call_tree).len();
+
+    match code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('j') | KeyCode::Down if app_state.call_tree_selected < total.saturating_sub(1) => {
+            app_state.call_tree_selected += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app_state.call_tree_selected = app_state.call_tree_selected.saturating_sub(1);
+        }
+        KeyCode::Char('g') => app_state.call_tree_selected = 0,
+        KeyCode::Char('G') => app_state.call_tree_selected = total.saturating_sub(1),
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            let visible = call_tree::visible_nodes(&app_state.call_tree);
+            if let Some((path, _)) = visible.get(app_state.call_tree_selected) {
+                if let Some(node) = call_tree::node_at_mut(&mut app_state.call_tree, path) {
+                    node.collapsed = !node.collapsed;
+                }
+            }
+        }
+        KeyCode::Char('d') => {
+            let visible = call_tree::visible_nodes(&app_state.call_tree);
+            if let Some((path, _)) = visible.get(app_state.call_tree_selected) {
+                if let Some(node) = call_tree::node_at(&app_state.call_tree, path) {
+                    app_state.selected_index = node.call_index;
+                }
+            }
+            app_state.view_mode = ViewMode::Details;
+        }
+        KeyCode::Char('c') => app_state.view_mode = ViewMode::Timeline,
+        _ => {}
+    }
+
+    if app_state.call_tree_selected >= app_state.call_tree_scroll + 20 {
+        app_state.call_tree_scroll = app_state.call_tree_selected.saturating_sub(19);
+    }
+    if app_state.call_tree_selected < app_state.call_tree_scroll {
+        app_state.call_tree_scroll = app_state.call_tree_selected;
+    }
+
+    false
+}
+
+/// Number of rows in the currently active non-timeline tab, so `j`/`k`
+/// selection stays within bounds of whatever that tab is showing.
+fn analytics_row_count(app_state: &AppState) -> usize {
+    match app_state.tabs.index {
+        1 => app_state.tool_stats.calls.len(),
+        2 => app_state.token_stats.by_model.len(),
+        3 => ui::SESSION_OVERVIEW_ROWS,
+        _ => 0,
+    }
+}
+
+/// Handles input while a non-timeline tab (Tool Analytics, LLM Breakdown,
+/// Session Overview) is active. Returns `true` if the app should quit.
+fn handle_analytics_key(app_state: &mut AppState, code: KeyCode) -> bool {
+    let total = analytics_row_count(app_state);
+
+    match code {
+        KeyCode::Char('q') => return true,
+        KeyCode::Char('j') | KeyCode::Down if app_state.tab_selected < total.saturating_sub(1) => {
+            app_state.tab_selected += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app_state.tab_selected = app_state.tab_selected.saturating_sub(1);
+        }
+        KeyCode::Char('g') => app_state.tab_selected = 0,
+        KeyCode::Char('G') => app_state.tab_selected = total.saturating_sub(1),
+        _ => {}
+    }
+
+    if app_state.tab_selected >= app_state.tab_scroll + VISIBLE_ROWS {
+        app_state.tab_scroll = app_state.tab_selected.saturating_sub(VISIBLE_ROWS - 1);
+    }
+    if app_state.tab_selected < app_state.tab_scroll {
+        app_state.tab_scroll = app_state.tab_selected;
+    }
+
+    false
+}
+
+/// Rows in a page jump, taken from the last-drawn timeline area's height so
+/// Ctrl-f/Ctrl-d etc. match what's actually on screen. Falls back to
+/// `VISIBLE_ROWS` before the first frame, when the rect is still default.
+fn page_size(app_state: &AppState) -> usize {
+    match app_state.timeline_area.height {
+        0 => VISIBLE_ROWS,
+        height => height.saturating_sub(2) as usize,
+    }
+}
+
+/// Applies a page- or edge-relative jump to `selected_index`, keeping it
+/// clamped to the filtered entry list and `scroll_offset` adjusted so it
+/// stays on-screen.
+fn apply_page_movement(app_state: &mut AppState, movement: PageMovement) {
+    let visible = ui::filtered_entry_indices(app_state);
+    let Some(last) = visible.len().checked_sub(1) else {
+        return;
+    };
+    let current = visible
+        .iter()
+        .position(|&i| i == app_state.selected_index)
+        .unwrap_or(0);
+    let page = page_size(app_state);
+    let half_page = page / 2;
+
+    let target = match movement {
+        PageMovement::FullDown => (current + page).min(last),
+        PageMovement::FullUp => current.saturating_sub(page),
+        PageMovement::HalfDown => (current + half_page).min(last),
+        PageMovement::HalfUp => current.saturating_sub(half_page),
+        PageMovement::Home => 0,
+        PageMovement::End => last,
+    };
+
+    app_state.selected_index = visible[target];
+    app_state.details_scroll_offset = 0;
+
+    if target >= app_state.scroll_offset + page {
+        app_state.scroll_offset = target.saturating_sub(page.saturating_sub(1));
+    }
+    if target < app_state.scroll_offset {
+        app_state.scroll_offset = target;
+    }
+}
+
+/// `scroll_offset` is a position index into the *filtered* entry list (see
+/// `draw_timeline`/`ui::filtered_entry_indices`), not a raw entry index. If
+/// `entry_index` doesn't pass the active event-type/diagnostics filters,
+/// they're cleared so the jump actually lands somewhere visible.
+fn scroll_to_filtered_position(app_state: &mut AppState, entry_index: usize) {
+    if !ui::filtered_entry_indices(app_state).contains(&entry_index) {
+        app_state.filter_event_type = None;
+        app_state.diagnostics_only = false;
+    }
+
+    let pos = ui::filtered_entry_indices(app_state)
+        .iter()
+        .position(|&i| i == entry_index)
+        .unwrap_or(0);
+
+    if pos >= app_state.scroll_offset + VISIBLE_ROWS || pos < app_state.scroll_offset {
+        app_state.scroll_offset = pos.saturating_sub(VISIBLE_ROWS / 2);
+    }
+}
+
+fn jump_to_search_match(app_state: &mut AppState) {
+    if let Some(m) = app_state.search_matches.get(app_state.search_cursor) {
+        let entry_index = m.entry_index;
+        app_state.selected_index = entry_index;
+        app_state.details_scroll_offset = 0;
+        scroll_to_filtered_position(app_state, entry_index);
+    }
+}
+
+/// Selects the entry flagged by the diagnostic at `diagnostic_cursor`,
+/// mirroring `jump_to_search_match` for `]`/`[` navigation.
+fn jump_to_diagnostic(app_state: &mut AppState) {
+    if let Some(d) = app_state.diagnostics.get(app_state.diagnostic_cursor) {
+        let entry_index = d.entry_index;
+        app_state.selected_index = entry_index;
+        app_state.details_scroll_offset = 0;
+        scroll_to_filtered_position(app_state, entry_index);
+    }
+}
+
+/// Lines read into memory per worker before that block is parsed and
+/// dropped. Bounds peak memory on multi-hundred-MB logs to roughly this
+/// many lines times the worker count, rather than the whole file.
+const READ_BLOCK_LINES_PER_WORKER: usize = 4096;
+
+/// Streams the file in bounded blocks, parsing each block across a worker
+/// pool sized to the machine's core count before reading the next one.
+/// `serde_json::from_str` per line is embarrassingly parallel, so each
+/// worker gets a contiguous slice of the current block and returns
+/// `(line_index, Result<LogEntry>)`; results are merged back by index so
+/// entry order and 1-based warning line numbers match the source file. Only
+/// one block's raw lines are held at a time, keeping cold start fast on
+/// multi-hundred-MB logs without buffering the whole file as one `Vec`.
 fn load_log_file(path: &PathBuf) -> Result<Vec<LogEntry>> {
     let file = File::open(path).context("Failed to open log file")?;
-    let reader = BufReader::new(file);
+    let mut reader = BufReader::new(file);
+
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let block_lines = worker_count * READ_BLOCK_LINES_PER_WORKER;
+
     let mut entries = Vec::new();
+    let mut lines_read = 0usize;
 
-    for (idx, line) in reader.lines().enumerate() {
-        let line = line.context(format!("Failed to read line {}", idx + 1))?;
-        if line.trim().is_empty() {
-            continue;
+    loop {
+        let mut block = Vec::with_capacity(block_lines);
+        for _ in 0..block_lines {
+            let mut line = String::new();
+            let bytes_read = reader
+                .read_line(&mut line)
+                .context("Failed to read log file")?;
+            if bytes_read == 0 {
+                break;
+            }
+            if line.ends_with('\n') {
+                line.pop();
+                if line.ends_with('\r') {
+                    line.pop();
+                }
+            }
+            block.push(line);
         }
 
-        match serde_json::from_str::<LogEntry>(&line) {
-            Ok(entry) => entries.push(entry),
-            Err(e) => eprintln!("Warning: Failed to parse line {}: {}", idx + 1, e),
+        if block.is_empty() {
+            break;
         }
+
+        let chunk_size = block.len().div_ceil(worker_count).max(1);
+        let mut parsed: Vec<(usize, serde_json::Result<LogEntry>)> =
+            Vec::with_capacity(block.len());
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = block
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(chunk_idx, chunk)| {
+                    let base = chunk_idx * chunk_size;
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, line)| !line.trim().is_empty())
+                            .map(|(offset, line)| {
+                                (base + offset, serde_json::from_str::<LogEntry>(line))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                parsed.extend(handle.join().expect("log parsing worker panicked"));
+            }
+        });
+
+        parsed.sort_by_key(|(idx, _)| *idx);
+
+        for (idx, result) in parsed {
+            match result {
+                Ok(entry) => entries.push(entry),
+                Err(e) => eprintln!("Warning: Failed to parse line {}: {}", lines_read + idx + 1, e),
+            }
+        }
+
+        lines_read += block.len();
     }
 
     Ok(entries)
@@ -258,13 +838,21 @@ fn calculate_token_stats(entries: &[LogEntry]) -> TokenStats {
     let mut total_tokens = 0u64;
     let mut total_calls = 0u32;
     let mut by_agent = HashMap::new();
+    let mut by_model: HashMap<String, ModelStats> = HashMap::new();
 
     for entry in entries {
         if let Some(llm_data) = entry.parse_llm_response() {
             total_calls += 1;
+            let model_stats = by_model.entry(llm_data.model.clone()).or_insert(ModelStats {
+                calls: 0,
+                tokens: 0,
+            });
+            model_stats.calls += 1;
+
             if let Some(tokens) = llm_data.tokens {
                 if let Some(t) = tokens.total {
                     total_tokens += t as u64;
+                    model_stats.tokens += t as u64;
                     if let Some(agent) = &entry.agent_name {
                         *by_agent.entry(agent.clone()).or_insert(0) += t as u64;
                     }
@@ -277,5 +865,6 @@ fn calculate_token_stats(entries: &[LogEntry]) -> TokenStats {
         total_tokens,
         total_calls,
         by_agent,
+        by_model,
     }
 }
@@ -31,6 +31,7 @@ pub struct ToolCallData {
     pub tool_name: String,
     pub params: HashMap<String, serde_json::Value>,
     pub result_summary: Option<String>,
+    pub tool_call_id: Option<String>,
     pub indent: Option<u32>,
 }
 
@@ -99,6 +100,7 @@ impl LogEntry {
             tool_name,
             params,
             result_summary: data.get("result_summary").and_then(|v| v.as_str()).map(String::from),
+            tool_call_id: data.get("tool_call_id").and_then(|v| v.as_str()).map(String::from),
             indent: data.get("indent").and_then(|v| v.as_u64()).map(|v| v as u32),
         })
     }
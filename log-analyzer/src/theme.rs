@@ -0,0 +1,268 @@
+//! Config-driven color theme with `NO_COLOR` support. Every color used by
+//! `ui.rs` is resolved through a `Theme` instead of being a hardcoded
+//! `Color::` constant, so a user config can recolor the viewer (or, on a
+//! monochrome terminal / with `NO_COLOR` set, flatten it to the terminal
+//! default).
+
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Theme {
+    selected_bg: Option<Color>,
+    accent: Option<Color>,
+    dim: Option<Color>,
+    success: Option<Color>,
+    error: Option<Color>,
+    warning: Option<Color>,
+    info: Option<Color>,
+    highlight: Option<Color>,
+    text: Option<Color>,
+    stripe: Option<Color>,
+    error_row_bg: Option<Color>,
+    event_colors: HashMap<String, Color>,
+    event_icons: HashMap<String, String>,
+    monochrome: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            selected_bg: None,
+            accent: None,
+            dim: None,
+            success: None,
+            error: None,
+            warning: None,
+            info: None,
+            highlight: None,
+            text: None,
+            stripe: None,
+            error_row_bg: None,
+            event_colors: default_event_colors(),
+            event_icons: default_event_icons(),
+            monochrome: false,
+        }
+    }
+}
+
+fn default_event_colors() -> HashMap<String, Color> {
+    HashMap::from([
+        ("llm_response".to_string(), Color::Blue),
+        ("tool_call".to_string(), Color::Green),
+        ("tool_result".to_string(), Color::Cyan),
+        ("agent_action".to_string(), Color::Yellow),
+        ("execution_summary".to_string(), Color::Magenta),
+        ("session_start".to_string(), Color::Green),
+        ("session_end".to_string(), Color::Red),
+    ])
+}
+
+fn default_event_icons() -> HashMap<String, String> {
+    HashMap::from([
+        ("llm_response".to_string(), "🤖".to_string()),
+        ("tool_call".to_string(), "🔧".to_string()),
+        ("tool_result".to_string(), "📦".to_string()),
+        ("agent_action".to_string(), "⚡".to_string()),
+        ("execution_summary".to_string(), "📊".to_string()),
+        ("session_start".to_string(), "🚀".to_string()),
+        ("session_end".to_string(), "🏁".to_string()),
+    ])
+}
+
+impl Theme {
+    /// Loads the built-in default, overlays a user config if `config_path`
+    /// is given and parses, then applies `NO_COLOR` if set.
+    pub fn load(config_path: Option<&Path>) -> Theme {
+        let mut theme = Theme::default();
+
+        if let Some(path) = config_path {
+            match ThemeConfig::from_file(path) {
+                Ok(config) => theme.extend(config.into_theme()),
+                Err(e) => eprintln!("Warning: Failed to load theme config: {}", e),
+            }
+        }
+
+        theme.monochrome = std::env::var_os("NO_COLOR").is_some();
+        theme
+    }
+
+    /// Overlays `other` on top of `self`: a field present in `other` wins,
+    /// a missing one falls back to whatever `self` already had.
+    pub fn extend(&mut self, other: Theme) {
+        if other.selected_bg.is_some() {
+            self.selected_bg = other.selected_bg;
+        }
+        if other.accent.is_some() {
+            self.accent = other.accent;
+        }
+        if other.dim.is_some() {
+            self.dim = other.dim;
+        }
+        if other.success.is_some() {
+            self.success = other.success;
+        }
+        if other.error.is_some() {
+            self.error = other.error;
+        }
+        if other.warning.is_some() {
+            self.warning = other.warning;
+        }
+        if other.info.is_some() {
+            self.info = other.info;
+        }
+        if other.highlight.is_some() {
+            self.highlight = other.highlight;
+        }
+        if other.text.is_some() {
+            self.text = other.text;
+        }
+        if other.stripe.is_some() {
+            self.stripe = other.stripe;
+        }
+        if other.error_row_bg.is_some() {
+            self.error_row_bg = other.error_row_bg;
+        }
+        self.event_colors.extend(other.event_colors);
+        self.event_icons.extend(other.event_icons);
+    }
+
+    fn resolve(&self, color: Option<Color>, fallback: Color) -> Color {
+        if self.monochrome {
+            Color::Reset
+        } else {
+            color.unwrap_or(fallback)
+        }
+    }
+
+    pub fn selected_bg(&self) -> Color {
+        self.resolve(self.selected_bg, Color::DarkGray)
+    }
+
+    pub fn accent(&self) -> Color {
+        self.resolve(self.accent, Color::Cyan)
+    }
+
+    pub fn dim(&self) -> Color {
+        self.resolve(self.dim, Color::DarkGray)
+    }
+
+    pub fn success(&self) -> Color {
+        self.resolve(self.success, Color::Green)
+    }
+
+    pub fn error(&self) -> Color {
+        self.resolve(self.error, Color::Red)
+    }
+
+    pub fn warning(&self) -> Color {
+        self.resolve(self.warning, Color::Yellow)
+    }
+
+    pub fn info(&self) -> Color {
+        self.resolve(self.info, Color::Blue)
+    }
+
+    pub fn highlight(&self) -> Color {
+        self.resolve(self.highlight, Color::Magenta)
+    }
+
+    pub fn text(&self) -> Color {
+        self.resolve(self.text, Color::White)
+    }
+
+    /// Subtle alternate-row background used to stripe the timeline.
+    pub fn stripe(&self) -> Color {
+        self.resolve(self.stripe, Color::Rgb(20, 20, 24))
+    }
+
+    /// Background tint for a `tool_result` row with `is_error` set.
+    pub fn error_row_bg(&self) -> Color {
+        self.resolve(self.error_row_bg, Color::Rgb(60, 10, 10))
+    }
+
+    pub fn event_color(&self, event_type: &str) -> Color {
+        if self.monochrome {
+            return Color::Reset;
+        }
+        self.event_colors.get(event_type).copied().unwrap_or(Color::Gray)
+    }
+
+    pub fn event_icon(&self, event_type: &str) -> &str {
+        self.event_icons.get(event_type).map(String::as_str).unwrap_or("•")
+    }
+}
+
+/// Raw, serde-facing shape of a theme config file (TOML or JSON). Colors are
+/// plain strings (`"cyan"`, `"#ff8800"`, ...) parsed via ratatui's `Color`
+/// `FromStr` impl.
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct ThemeConfig {
+    selected_bg: Option<String>,
+    accent: Option<String>,
+    dim: Option<String>,
+    success: Option<String>,
+    error: Option<String>,
+    warning: Option<String>,
+    info: Option<String>,
+    highlight: Option<String>,
+    text: Option<String>,
+    stripe: Option<String>,
+    error_row_bg: Option<String>,
+    event_colors: HashMap<String, String>,
+    event_icons: HashMap<String, String>,
+}
+
+impl ThemeConfig {
+    fn from_file(path: &Path) -> anyhow::Result<ThemeConfig> {
+        let contents = std::fs::read_to_string(path)?;
+        let is_json = path.extension().and_then(|e| e.to_str()) == Some("json");
+
+        if is_json {
+            Ok(serde_json::from_str(&contents)?)
+        } else {
+            Ok(toml::from_str(&contents)?)
+        }
+    }
+
+    fn into_theme(self) -> Theme {
+        let mut theme = Theme {
+            selected_bg: parse_color(self.selected_bg),
+            accent: parse_color(self.accent),
+            dim: parse_color(self.dim),
+            success: parse_color(self.success),
+            error: parse_color(self.error),
+            warning: parse_color(self.warning),
+            info: parse_color(self.info),
+            highlight: parse_color(self.highlight),
+            text: parse_color(self.text),
+            stripe: parse_color(self.stripe),
+            error_row_bg: parse_color(self.error_row_bg),
+            event_colors: HashMap::new(),
+            event_icons: self.event_icons,
+            monochrome: false,
+        };
+
+        for (event_type, color) in self.event_colors {
+            if let Some(color) = parse_color(Some(color)) {
+                theme.event_colors.insert(event_type, color);
+            }
+        }
+
+        theme
+    }
+}
+
+fn parse_color(value: Option<String>) -> Option<Color> {
+    value.and_then(|s| match Color::from_str(&s) {
+        Ok(color) => Some(color),
+        Err(_) => {
+            eprintln!("Warning: Unrecognized theme color \"{}\"", s);
+            None
+        }
+    })
+}
@@ -1,31 +1,86 @@
-use crate::{AppState, ViewMode};
+use crate::models::LogEntry;
+use crate::{AppState, InputMode, ViewMode};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
+
+pub fn draw_ui(f: &mut Frame, app_state: &mut AppState) {
+    let search_active = app_state.input_mode == InputMode::Search;
+
+    let mut constraints = vec![
+        Constraint::Length(3), // Tab strip
+        Constraint::Length(8), // Stats panel (expanded for session ID)
+        Constraint::Min(10),   // Main content
+    ];
+    if search_active {
+        constraints.push(Constraint::Length(3)); // Search bar
+    }
+    constraints.push(Constraint::Length(3)); // Help bar
 
-pub fn draw_ui(f: &mut Frame, app_state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(8),  // Stats panel (expanded for session ID)
-            Constraint::Min(10),    // Main content
-            Constraint::Length(3),  // Help bar
-        ])
+        .constraints(constraints)
         .split(f.area());
 
-    draw_stats_panel(f, chunks[0], app_state);
-    draw_main_content(f, chunks[1], app_state);
-    draw_help_bar(f, chunks[2], app_state);
+    draw_tabs(f, chunks[0], app_state);
+    draw_stats_panel(f, chunks[1], app_state);
+    draw_main_content(f, chunks[2], app_state);
+
+    if search_active {
+        draw_search_bar(f, chunks[3], app_state);
+        draw_help_bar(f, chunks[4], app_state);
+    } else {
+        draw_help_bar(f, chunks[3], app_state);
+    }
+}
+
+fn draw_tabs(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let titles: Vec<Line> = app_state
+        .tabs
+        .titles
+        .iter()
+        .map(|title| Line::from(Span::raw(title.clone())))
+        .collect();
+
+    let tabs = Tabs::new(titles)
+        .block(Block::default().title("Tab/Shift-Tab").borders(Borders::ALL))
+        .select(app_state.tabs.index)
+        .style(Style::default().fg(app_state.theme.dim()))
+        .highlight_style(Style::default().fg(app_state.theme.accent()).add_modifier(Modifier::BOLD));
+    f.render_widget(tabs, area);
+}
+
+fn draw_search_bar(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let match_info = if app_state.search_query.is_empty() {
+        String::new()
+    } else {
+        format!(" ({} matches)", app_state.search_matches.len())
+    };
+
+    let line = Line::from(vec![
+        Span::styled("/", Style::default().fg(app_state.theme.warning())),
+        Span::raw(&app_state.search_query),
+        Span::styled(match_info, Style::default().fg(app_state.theme.dim())),
+    ]);
+
+    let search_bar = Paragraph::new(line)
+        .block(Block::default().title("Search").borders(Borders::ALL));
+    f.render_widget(search_bar, area);
 }
 
 fn draw_stats_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(34),
+            Constraint::Percentage(33),
+            Constraint::Percentage(33),
+        ])
         .split(area);
 
     // Session info
@@ -37,25 +92,25 @@ fn draw_stats_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Token stats
     let token_text = vec![
         Line::from(vec![
-            Span::styled("Session: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Session: ", Style::default().fg(app_state.theme.accent())),
             Span::styled(
                 format!("{}", session_id),
-                Style::default().fg(Color::Green),
+                Style::default().fg(app_state.theme.success()),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Total Tokens: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Total Tokens: ", Style::default().fg(app_state.theme.accent())),
             Span::styled(
                 format!("{}", app_state.token_stats.total_tokens),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app_state.theme.warning()),
             ),
         ]),
         Line::from(vec![
-            Span::styled("LLM Calls: ", Style::default().fg(Color::Cyan)),
+            Span::styled("LLM Calls: ", Style::default().fg(app_state.theme.accent())),
             Span::raw(format!("{}", app_state.token_stats.total_calls)),
         ]),
         Line::from(vec![
-            Span::styled("Total Events: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Total Events: ", Style::default().fg(app_state.theme.accent())),
             Span::raw(format!("{}", app_state.entries.len())),
         ]),
     ];
@@ -67,7 +122,7 @@ fn draw_stats_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Tool stats
     let mut tool_lines = vec![Line::from(Span::styled(
         "Tool Usage:",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        Style::default().fg(app_state.theme.accent()).add_modifier(Modifier::BOLD),
     ))];
 
     let mut tools: Vec<_> = app_state.tool_stats.calls.iter().collect();
@@ -79,11 +134,11 @@ fn draw_stats_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
 
         tool_lines.push(Line::from(vec![
             Span::raw(format!("  {}: ", tool)),
-            Span::styled(format!("{}", count), Style::default().fg(Color::White)),
+            Span::styled(format!("{}", count), Style::default().fg(app_state.theme.text())),
             Span::raw(" ("),
-            Span::styled(format!("✓{}", success), Style::default().fg(Color::Green)),
+            Span::styled(format!("✓{}", success), Style::default().fg(app_state.theme.success())),
             Span::raw("/"),
-            Span::styled(format!("✗{}", errors), Style::default().fg(Color::Red)),
+            Span::styled(format!("✗{}", errors), Style::default().fg(app_state.theme.error())),
             Span::raw(")"),
         ]));
     }
@@ -91,39 +146,377 @@ fn draw_stats_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
     let tool_panel = Paragraph::new(tool_lines)
         .block(Block::default().title("Top Tools").borders(Borders::ALL));
     f.render_widget(tool_panel, chunks[1]);
+
+    draw_diagnostics_summary(f, chunks[2], app_state);
 }
 
-fn draw_main_content(f: &mut Frame, area: Rect, app_state: &AppState) {
-    if app_state.view_mode == ViewMode::Details {
-        let chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(area);
-        draw_timeline(f, chunks[0], app_state);
-        draw_details_panel(f, chunks[1], app_state);
-    } else {
-        draw_timeline(f, area, app_state);
+fn draw_diagnostics_summary(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let errors = app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == crate::diagnostics::Severity::Error)
+        .count();
+    let warnings = app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == crate::diagnostics::Severity::Warning)
+        .count();
+    let info = app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == crate::diagnostics::Severity::Info)
+        .count();
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "Diagnostics:",
+            Style::default().fg(app_state.theme.accent()).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(format!("✗{}", errors), Style::default().fg(app_state.theme.error())),
+            Span::raw(" "),
+            Span::styled(format!("⚠{}", warnings), Style::default().fg(app_state.theme.warning())),
+            Span::raw(" "),
+            Span::styled(format!("ℹ{}", info), Style::default().fg(app_state.theme.info())),
+        ]),
+        Line::from(Span::styled(
+            "x:toggle flagged-only view  ]/[:next/prev",
+            Style::default().fg(app_state.theme.dim()),
+        )),
+    ];
+
+    let panel = Paragraph::new(lines)
+        .block(Block::default().title("Diagnostics").borders(Borders::ALL));
+    f.render_widget(panel, area);
+}
+
+fn draw_main_content(f: &mut Frame, area: Rect, app_state: &mut AppState) {
+    if app_state.tabs.index != 0 {
+        // Only the Timeline tab (0) shows the timeline/details panes;
+        // clear their hit-test areas so stale rects don't catch clicks.
+        app_state.timeline_area = Rect::default();
+        app_state.details_area = Rect::default();
+    }
+
+    match app_state.tabs.index {
+        1 => return draw_tool_analytics(f, area, app_state),
+        2 => return draw_llm_breakdown(f, area, app_state),
+        3 => return draw_session_overview(f, area, app_state),
+        _ => {}
+    }
+
+    match app_state.view_mode {
+        ViewMode::Details => {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(area);
+            draw_timeline(f, chunks[0], app_state);
+            draw_details_panel(f, chunks[1], app_state);
+        }
+        ViewMode::CallTree => {
+            app_state.details_area = Rect::default();
+            app_state.timeline_area = Rect::default();
+            draw_call_tree(f, area, app_state);
+        }
+        ViewMode::Timeline => {
+            app_state.details_area = Rect::default();
+            draw_timeline(f, area, app_state);
+        }
     }
 }
 
-fn draw_timeline(f: &mut Frame, area: Rect, app_state: &AppState) {
+fn draw_tool_analytics(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let mut tools: Vec<_> = app_state.tool_stats.calls.iter().collect();
+    tools.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+
+    let items: Vec<ListItem> = tools
+        .iter()
+        .enumerate()
+        .skip(app_state.tab_scroll)
+        .take(area.height.saturating_sub(2) as usize)
+        .map(|(row, (tool, count))| {
+            let success = *app_state.tool_stats.success.get(*tool).unwrap_or(&0);
+            let errors = *app_state.tool_stats.errors.get(*tool).unwrap_or(&0);
+            let total_results = success + errors;
+            let success_rate = if total_results > 0 {
+                format!("{:.0}%", success as f64 / total_results as f64 * 100.0)
+            } else {
+                "n/a".to_string()
+            };
+
+            let line = Line::from(vec![
+                Span::styled(format!("{:<24}", tool), Style::default().fg(app_state.theme.text())),
+                Span::raw(format!("calls: {:<6}", count)),
+                Span::styled(format!("✓{:<5}", success), Style::default().fg(app_state.theme.success())),
+                Span::styled(format!("✗{:<5}", errors), Style::default().fg(app_state.theme.error())),
+                Span::raw(format!("success: {}", success_rate)),
+            ]);
+
+            let style = if row == app_state.tab_selected {
+                Style::default().bg(app_state.theme.selected_bg()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let title = format!(
+        "Tool Analytics ({}/{} tools)",
+        (app_state.tab_selected + 1).min(tools.len().max(1)),
+        tools.len()
+    );
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(list, area);
+}
+
+fn draw_llm_breakdown(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let mut models: Vec<_> = app_state.token_stats.by_model.iter().collect();
+    models.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.tokens));
+
+    let items: Vec<ListItem> = models
+        .iter()
+        .enumerate()
+        .skip(app_state.tab_scroll)
+        .take(area.height.saturating_sub(2) as usize)
+        .map(|(row, (model, stats))| {
+            let avg = if stats.calls > 0 {
+                stats.tokens / stats.calls as u64
+            } else {
+                0
+            };
+
+            let line = Line::from(vec![
+                Span::styled(format!("{:<24}", model), Style::default().fg(app_state.theme.accent())),
+                Span::raw(format!("calls: {:<6}", stats.calls)),
+                Span::styled(format!("tokens: {:<10}", stats.tokens), Style::default().fg(app_state.theme.warning())),
+                Span::raw(format!("avg/call: {}", avg)),
+            ]);
+
+            let style = if row == app_state.tab_selected {
+                Style::default().bg(app_state.theme.selected_bg()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let title = format!(
+        "LLM Breakdown ({}/{} models)",
+        (app_state.tab_selected + 1).min(models.len().max(1)),
+        models.len()
+    );
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(list, area);
+}
+
+/// Number of lines `draw_session_overview` renders, kept in sync with the
+/// `lines` vec below so `j`/`k` selection in `main.rs` knows its bounds.
+pub(crate) const SESSION_OVERVIEW_ROWS: usize = 12;
+
+fn draw_session_overview(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let session_id = app_state
+        .entries
+        .first()
+        .and_then(|e| e.session_id.as_ref())
+        .map(|s| s.as_str())
+        .unwrap_or("unknown");
+
+    let errors = app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == crate::diagnostics::Severity::Error)
+        .count();
+    let warnings = app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == crate::diagnostics::Severity::Warning)
+        .count();
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Session: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(session_id),
+        ]),
+        Line::from(vec![
+            Span::styled("Total Events: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.entries.len())),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Tool Calls: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.tool_stats.calls.values().sum::<u32>())),
+        ]),
+        Line::from(vec![
+            Span::styled("Distinct Tools: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.tool_stats.calls.len())),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("LLM Calls: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.token_stats.total_calls)),
+        ]),
+        Line::from(vec![
+            Span::styled("Total Tokens: ", Style::default().fg(app_state.theme.accent())),
+            Span::styled(
+                format!("{}", app_state.token_stats.total_tokens),
+                Style::default().fg(app_state.theme.warning()),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Distinct Models: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.token_stats.by_model.len())),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Call Tree Roots: ", Style::default().fg(app_state.theme.accent())),
+            Span::raw(format!("{}", app_state.call_tree.roots.len())),
+        ]),
+        Line::from(vec![
+            Span::styled("Diagnostics: ", Style::default().fg(app_state.theme.accent())),
+            Span::styled(format!("✗{}", errors), Style::default().fg(app_state.theme.error())),
+            Span::raw(" "),
+            Span::styled(format!("⚠{}", warnings), Style::default().fg(app_state.theme.warning())),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().title("Session Overview").borders(Borders::ALL))
+        .scroll((app_state.tab_scroll as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn draw_call_tree(f: &mut Frame, area: Rect, app_state: &AppState) {
     let entries = &app_state.entries;
+    let visible = crate::call_tree::visible_nodes(&app_state.call_tree);
+
+    let items: Vec<ListItem> = visible
+        .iter()
+        .enumerate()
+        .skip(app_state.call_tree_scroll)
+        .take(area.height.saturating_sub(2) as usize)
+        .filter_map(|(row, (path, depth))| {
+            let node = crate::call_tree::node_at(&app_state.call_tree, path)?;
+            let call = entries[node.call_index].parse_tool_call()?;
+
+            let status = match node.result_index {
+                Some(_) if node.is_error(entries) => {
+                    Span::styled("✗", Style::default().fg(app_state.theme.error()))
+                }
+                Some(_) => Span::styled("✓", Style::default().fg(app_state.theme.success())),
+                None => Span::styled("…", Style::default().fg(app_state.theme.dim())),
+            };
+
+            let toggle = if node.children.is_empty() {
+                " "
+            } else if node.collapsed {
+                "+"
+            } else {
+                "-"
+            };
+
+            let latency = node
+                .own_latency_ms(entries)
+                .map(|ms| format!(" ({ms}ms, total {}ms)", node.aggregate_latency_ms(entries)))
+                .unwrap_or_default();
+
+            let line = Line::from(vec![
+                Span::raw("  ".repeat(*depth)),
+                Span::styled(toggle, Style::default().fg(app_state.theme.dim())),
+                Span::raw(" "),
+                status,
+                Span::raw(" "),
+                Span::styled(call.tool_name, Style::default().fg(app_state.theme.success())),
+                Span::styled(latency, Style::default().fg(app_state.theme.dim())),
+            ]);
+
+            let style = if row == app_state.call_tree_selected {
+                Style::default().bg(app_state.theme.selected_bg()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            Some(ListItem::new(line).style(style))
+        })
+        .collect();
 
-    let filtered_entries: Vec<_> = if let Some(ref filter) = app_state.filter_event_type {
-        entries
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| e.event_type == *filter)
-            .collect()
+    let title = format!(
+        "Call Tree ({}/{})",
+        (app_state.call_tree_selected + 1).min(visible.len().max(1)),
+        visible.len()
+    );
+
+    let list = List::new(items).block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(list, area);
+}
+
+/// Resolves the background/style for one timeline row from selection state,
+/// whether it's an errored `tool_result`, and the filtered position's
+/// parity — in that priority order.
+fn row_style(app_state: &AppState, entry: &LogEntry, filtered_idx: usize, is_selected: bool) -> Style {
+    if is_selected {
+        return Style::default()
+            .bg(app_state.theme.selected_bg())
+            .add_modifier(Modifier::BOLD);
+    }
+
+    let is_error_result = entry.event_type == "tool_result"
+        && entry.parse_tool_result().map(|r| r.is_error).unwrap_or(false);
+    if is_error_result {
+        return Style::default().bg(app_state.theme.error_row_bg());
+    }
+
+    if filtered_idx % 2 == 1 {
+        Style::default().bg(app_state.theme.stripe())
     } else {
-        entries.iter().enumerate().collect()
-    };
+        Style::default()
+    }
+}
+
+/// Indices into `app_state.entries` that pass the active event-type/diagnostics
+/// filters, in display order — the same ordering `draw_timeline` renders, so
+/// mouse hit-testing can map a clicked row back to the right entry.
+pub(crate) fn filtered_entry_indices(app_state: &AppState) -> Vec<usize> {
+    let flagged: std::collections::HashSet<usize> = app_state
+        .diagnostics
+        .iter()
+        .map(|d| d.entry_index)
+        .collect();
+
+    app_state
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(idx, e)| {
+            let passes_event_filter = app_state
+                .filter_event_type
+                .as_ref()
+                .map(|f| e.event_type == *f)
+                .unwrap_or(true);
+            let passes_diagnostics_filter = !app_state.diagnostics_only || flagged.contains(idx);
+            passes_event_filter && passes_diagnostics_filter
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn draw_timeline(f: &mut Frame, area: Rect, app_state: &mut AppState) {
+    app_state.timeline_area = area;
+    let entries = &app_state.entries;
+
+    let filtered_indices = filtered_entry_indices(app_state);
+    let filtered_entries: Vec<_> = filtered_indices.iter().map(|&idx| (idx, &entries[idx])).collect();
 
     let items: Vec<ListItem> = filtered_entries
         .iter()
+        .enumerate()
         .skip(app_state.scroll_offset)
-        .take(area.height as usize - 2)
-        .map(|(idx, entry)| {
+        .take(area.height.saturating_sub(2) as usize)
+        .map(|(filtered_idx, (idx, entry))| {
             let is_selected = *idx == app_state.selected_index;
 
             let time = entry
@@ -133,20 +526,22 @@ fn draw_timeline(f: &mut Frame, area: Rect, app_state: &AppState) {
                 .and_then(|t| t.split('.').next())
                 .unwrap_or(&entry.timestamp);
 
-            let (icon, color, detail) = match entry.event_type.as_str() {
+            let icon = app_state.theme.event_icon(&entry.event_type);
+            let color = app_state.theme.event_color(&entry.event_type);
+            let detail = match entry.event_type.as_str() {
                 "llm_response" => {
                     let model = entry
                         .parse_llm_response()
                         .map(|l| l.model)
                         .unwrap_or_else(|| "unknown".to_string());
-                    ("🤖", Color::Blue, format!("LLM: {}", model))
+                    format!("LLM: {}", model)
                 }
                 "tool_call" => {
                     let tool = entry
                         .parse_tool_call()
                         .map(|t| t.tool_name)
                         .unwrap_or_else(|| "unknown".to_string());
-                    ("🔧", Color::Green, format!("Tool Call: {}", tool))
+                    format!("Tool Call: {}", tool)
                 }
                 "tool_result" => {
                     let result = entry.parse_tool_result();
@@ -158,19 +553,19 @@ fn draw_timeline(f: &mut Frame, area: Rect, app_state: &AppState) {
                         .as_ref()
                         .map(|r| if r.is_error { "✗" } else { "✓" })
                         .unwrap_or("?");
-                    ("📦", Color::Cyan, format!("Result {}: {}", status, tool))
+                    format!("Result {}: {}", status, tool)
                 }
                 "agent_action" => {
                     let action = entry
                         .parse_agent_action()
                         .map(|a| a.action)
                         .unwrap_or_else(|| "unknown".to_string());
-                    ("⚡", Color::Yellow, format!("Action: {}", action))
+                    format!("Action: {}", action)
                 }
-                "execution_summary" => ("📊", Color::Magenta, "Execution Summary".to_string()),
-                "session_start" => ("🚀", Color::Green, "Session Start".to_string()),
-                "session_end" => ("🏁", Color::Red, "Session End".to_string()),
-                _ => ("•", Color::Gray, entry.event_type.clone()),
+                "execution_summary" => "Execution Summary".to_string(),
+                "session_start" => "Session Start".to_string(),
+                "session_end" => "Session End".to_string(),
+                _ => entry.event_type.clone(),
             };
 
             let agent = entry
@@ -180,32 +575,29 @@ fn draw_timeline(f: &mut Frame, area: Rect, app_state: &AppState) {
                 .unwrap_or_else(|| "".to_string());
 
             let line = Line::from(vec![
-                Span::styled(time, Style::default().fg(Color::DarkGray)),
+                Span::styled(time, Style::default().fg(app_state.theme.dim())),
                 Span::raw(" "),
                 Span::styled(icon, Style::default().fg(color)),
                 Span::raw(" "),
                 Span::styled(detail, Style::default().fg(color)),
                 Span::raw(" "),
-                Span::styled(agent, Style::default().fg(Color::Cyan)),
+                Span::styled(agent, Style::default().fg(app_state.theme.accent())),
             ]);
 
-            let style = if is_selected {
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default()
-            };
+            let style = row_style(app_state, entry, filtered_idx, is_selected);
 
             ListItem::new(line).style(style)
         })
         .collect();
 
-    let filter_info = if let Some(ref f) = app_state.filter_event_type {
+    let mut filter_info = if let Some(ref f) = app_state.filter_event_type {
         format!(" [Filter: {}]", f)
     } else {
         "".to_string()
     };
+    if app_state.diagnostics_only {
+        filter_info.push_str(" [Diagnostics only]");
+    }
 
     let title = format!(
         "Timeline ({}/{}){}",
@@ -219,7 +611,42 @@ fn draw_timeline(f: &mut Frame, area: Rect, app_state: &AppState) {
     f.render_widget(list, area);
 }
 
-fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
+/// Number of rendered rows a `Line` occupies once word-wrapped (with trimming)
+/// to `width` columns, matching the `Wrap { trim: true }` behavior used below.
+///
+/// Uses rendered terminal-column width (via `unicode_width`), not `chars().count()`,
+/// so wide characters (CJK, emoji, ...) don't make this undercount rows and
+/// clamp `max_scroll` below the text's true length.
+fn wrapped_row_count(line: &Line, width: u16) -> usize {
+    let width = width.max(1) as usize;
+    let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+
+    let mut rows = 0usize;
+    let mut current_width = 0usize;
+    for word in text.split_whitespace() {
+        let mut word_len = word.width();
+        if current_width > 0 && current_width + 1 + word_len <= width {
+            current_width += 1 + word_len;
+            continue;
+        }
+        if current_width > 0 {
+            rows += 1;
+        }
+        // A single word longer than the available width still wraps onto extra rows.
+        while word_len > width {
+            rows += 1;
+            word_len -= width;
+        }
+        current_width = word_len;
+    }
+    if current_width > 0 {
+        rows += 1;
+    }
+    rows.max(1)
+}
+
+fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &mut AppState) {
+    app_state.details_area = area;
     if app_state.selected_index >= app_state.entries.len() {
         return;
     }
@@ -227,22 +654,41 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
     let entry = &app_state.entries[app_state.selected_index];
     let mut lines = vec![
         Line::from(vec![
-            Span::styled("Event: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Event: ", Style::default().fg(app_state.theme.accent())),
             Span::raw(&entry.event_type),
         ]),
         Line::from(vec![
-            Span::styled("Time: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Time: ", Style::default().fg(app_state.theme.accent())),
             Span::raw(&entry.timestamp),
         ]),
     ];
 
     if let Some(agent) = &entry.agent_name {
         lines.push(Line::from(vec![
-            Span::styled("Agent: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Agent: ", Style::default().fg(app_state.theme.accent())),
             Span::raw(agent),
         ]));
     }
 
+    for diagnostic in app_state
+        .diagnostics
+        .iter()
+        .filter(|d| d.entry_index == app_state.selected_index)
+    {
+        let color = match diagnostic.severity {
+            crate::diagnostics::Severity::Error => app_state.theme.error(),
+            crate::diagnostics::Severity::Warning => app_state.theme.warning(),
+            crate::diagnostics::Severity::Info => app_state.theme.info(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("[{}] ", diagnostic.rule_name),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(diagnostic.message.clone()),
+        ]));
+    }
+
     lines.push(Line::from(""));
 
     match entry.event_type.as_str() {
@@ -250,7 +696,7 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
             if let Some(llm) = entry.parse_llm_response() {
                 lines.push(Line::from(Span::styled(
                     "Model:",
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    Style::default().fg(app_state.theme.warning()).add_modifier(Modifier::BOLD),
                 )));
                 lines.push(Line::from(format!("  {}", llm.model)));
 
@@ -258,7 +704,7 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
                     lines.push(Line::from(""));
                     lines.push(Line::from(Span::styled(
                         "Tokens:",
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app_state.theme.warning()).add_modifier(Modifier::BOLD),
                     )));
                     if let Some(total) = tokens.total {
                         lines.push(Line::from(format!("  Total: {}", total)));
@@ -275,9 +721,9 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
                     lines.push(Line::from(""));
                     lines.push(Line::from(Span::styled(
                         "Reasoning:",
-                        Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app_state.theme.highlight()).add_modifier(Modifier::BOLD),
                     )));
-                    for line in reasoning.lines().take(10) {
+                    for line in reasoning.lines() {
                         lines.push(Line::from(format!("  {}", line)));
                     }
                 }
@@ -286,9 +732,9 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
                     lines.push(Line::from(""));
                     lines.push(Line::from(Span::styled(
                         "Content:",
-                        Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app_state.theme.info()).add_modifier(Modifier::BOLD),
                     )));
-                    for line in content.lines().take(10) {
+                    for line in content.lines() {
                         lines.push(Line::from(format!("  {}", line)));
                     }
                 }
@@ -298,7 +744,7 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
             if let Some(tool) = entry.parse_tool_call() {
                 lines.push(Line::from(Span::styled(
                     "Tool:",
-                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                    Style::default().fg(app_state.theme.success()).add_modifier(Modifier::BOLD),
                 )));
                 lines.push(Line::from(format!("  {}", tool.tool_name)));
 
@@ -306,7 +752,7 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
                     lines.push(Line::from(""));
                     lines.push(Line::from(Span::styled(
                         "Parameters:",
-                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                        Style::default().fg(app_state.theme.success()).add_modifier(Modifier::BOLD),
                     )));
                     for (key, value) in tool.params.iter() {
                         let val_str = format!("{}", value);
@@ -318,24 +764,24 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
         "tool_result" => {
             if let Some(result) = entry.parse_tool_result() {
                 lines.push(Line::from(vec![
-                    Span::styled("Tool: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Tool: ", Style::default().fg(app_state.theme.accent())),
                     Span::raw(result.tool_name.clone()),
                 ]));
 
                 let status = if result.is_error {
-                    Span::styled("✗ Error", Style::default().fg(Color::Red))
+                    Span::styled("✗ Error", Style::default().fg(app_state.theme.error()))
                 } else {
-                    Span::styled("✓ Success", Style::default().fg(Color::Green))
+                    Span::styled("✓ Success", Style::default().fg(app_state.theme.success()))
                 };
-                lines.push(Line::from(vec![Span::styled("Status: ", Style::default().fg(Color::Cyan)), status]));
+                lines.push(Line::from(vec![Span::styled("Status: ", Style::default().fg(app_state.theme.accent())), status]));
 
                 lines.push(Line::from(""));
                 lines.push(Line::from(Span::styled(
                     "Result:",
-                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    Style::default().fg(app_state.theme.accent()).add_modifier(Modifier::BOLD),
                 )));
 
-                for line in result.result_content.lines().take(15) {
+                for line in result.result_content.lines() {
                     lines.push(Line::from(format!("  {}", line)));
                 }
             }
@@ -344,20 +790,38 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
             if let Some(data) = &entry.data {
                 lines.push(Line::from(Span::styled(
                     "Data:",
-                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    Style::default().fg(app_state.theme.accent()).add_modifier(Modifier::BOLD),
                 )));
                 let data_str = serde_json::to_string_pretty(data).unwrap_or_default();
-                for line in data_str.lines().take(20) {
+                for line in data_str.lines() {
                     lines.push(Line::from(format!("  {}", line)));
                 }
             }
         }
     }
 
+    // `area.width` minus the block borders is the actual text width the Paragraph wraps to.
+    let text_width = area.width.saturating_sub(2);
+    let total_rows: usize = lines.iter().map(|line| wrapped_row_count(line, text_width)).sum();
+    let visible_rows = area.height.saturating_sub(2) as usize;
+    let max_scroll = total_rows.saturating_sub(visible_rows);
+    app_state.details_scroll_offset = app_state.details_scroll_offset.min(max_scroll);
+
+    let more_below = total_rows.saturating_sub(app_state.details_scroll_offset + visible_rows);
+    let mut title = if app_state.details_focused {
+        "Details [focused]".to_string()
+    } else {
+        "Details".to_string()
+    };
+    if more_below > 0 {
+        title.push_str(&format!(" ({} more lines below)", more_below));
+    }
+
     let text = Text::from(lines);
     let paragraph = Paragraph::new(text)
-        .block(Block::default().title("Details").borders(Borders::ALL))
-        .wrap(Wrap { trim: true });
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: true })
+        .scroll((app_state.details_scroll_offset as u16, 0));
 
     f.render_widget(paragraph, area);
 }
@@ -365,22 +829,34 @@ fn draw_details_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
 fn draw_help_bar(f: &mut Frame, area: Rect, app_state: &AppState) {
     let mut help_spans = vec![
         Span::raw(" "),
-        Span::styled("q", Style::default().fg(Color::Yellow)),
+        Span::styled("q", Style::default().fg(app_state.theme.warning())),
         Span::raw(":Quit "),
-        Span::styled("[count]j/k", Style::default().fg(Color::Yellow)),
+        Span::styled("[count]j/k", Style::default().fg(app_state.theme.warning())),
         Span::raw(":Navigate "),
-        Span::styled("d", Style::default().fg(Color::Yellow)),
+        Span::styled("d", Style::default().fg(app_state.theme.warning())),
         Span::raw(":Details "),
-        Span::styled("f", Style::default().fg(Color::Yellow)),
+        Span::styled("c", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Call Tree "),
+        Span::styled("f", Style::default().fg(app_state.theme.warning())),
         Span::raw(":Filter "),
-        Span::styled("g/G", Style::default().fg(Color::Yellow)),
+        Span::styled("x", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Diagnostics "),
+        Span::styled("]/[", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Next/Prev Diagnostic "),
+        Span::styled("g/G", Style::default().fg(app_state.theme.warning())),
         Span::raw(":Top/Bottom "),
+        Span::styled("/", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Search "),
+        Span::styled("n/N", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Next/Prev Match "),
+        Span::styled("Tab/S-Tab", Style::default().fg(app_state.theme.warning())),
+        Span::raw(":Switch View "),
         Span::raw(format!(
             " | Mode: {}",
-            if app_state.view_mode == ViewMode::Details {
-                "Details"
-            } else {
-                "Timeline"
+            match app_state.view_mode {
+                ViewMode::Details => "Details",
+                ViewMode::CallTree => "Call Tree",
+                ViewMode::Timeline => "Timeline",
             }
         )),
     ];
@@ -390,7 +866,7 @@ fn draw_help_bar(f: &mut Frame, area: Rect, app_state: &AppState) {
         help_spans.push(Span::raw(" | "));
         help_spans.push(Span::styled(
             format!("Count: {}", app_state.count_prefix),
-            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            Style::default().fg(app_state.theme.success()).add_modifier(Modifier::BOLD),
         ));
     }
 